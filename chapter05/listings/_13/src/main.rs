@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use std::fmt;
+
+#[derive(Debug, Hash, PartialEq, Eq)]
 struct Rectangle {
     width: u32,
     height: u32,
@@ -7,12 +9,23 @@ struct Rectangle {
 // To define the function within the context of `Rectangle`, we start an `impl`
 // (implementation) block
 impl Rectangle {
-    // function within `impl` block with `self` as first parameter is a method 
+    // function within `impl` block with `self` as first parameter is a method
     fn area(&self) -> u32 {
         self.width * self.height
     }
 }
 
+// f.pad honors the format spec's width, fill, and alignment (e.g.
+// `format!("{:<8}", rect)` left-aligns) for the rendered "WxH" output;
+// Rectangle has no meaningful precision to honor
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rendered = format!("{}x{}", self.width, self.height);
+
+        f.pad(&rendered)
+    }
+}
+
 fn main() {
     let rect1 = Rectangle {
         width: 30,
@@ -25,4 +38,6 @@ fn main() {
         // parentheses, and any arguments
         rect1.area()
     );
+
+    println!("{:>8}", rect1);
 }
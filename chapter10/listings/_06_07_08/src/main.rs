@@ -1,6 +1,11 @@
+use std::fmt;
+use std::fmt::Write as _;
+use std::ops::{Add, Mul, Shl, Shr, Sub};
+
 // declare the name of the type parameter inside angle brackets
 // just after the name of the struct
 // @note x and y must be of the same type
+#[derive(Debug, PartialEq, Eq, Hash)]
 struct Point<T> {
     x: T,
     y: T,
@@ -12,11 +17,109 @@ struct PointV2<T, U> {
     y: U,
 }
 
+// component-wise addition, e.g. Point { x: 1, y: 2 } + Point { x: 3, y: 4 }
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// component-wise subtraction
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+// component-wise multiplication
+impl<T: Mul<Output = T>> Mul for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, other: Point<T>) -> Point<T> {
+        Point {
+            x: self.x * other.x,
+            y: self.y * other.y,
+        }
+    }
+}
+
+// shift both coordinates left by the same amount, e.g. Point { x, y } << 2
+impl<T: Shl<u32, Output = T>> Shl<u32> for Point<T> {
+    type Output = Point<T>;
+
+    fn shl(self, rhs: u32) -> Point<T> {
+        Point {
+            x: self.x << rhs,
+            y: self.y << rhs,
+        }
+    }
+}
+
+// shift both coordinates right by the same amount
+impl<T: Shr<u32, Output = T>> Shr<u32> for Point<T> {
+    type Output = Point<T>;
+
+    fn shr(self, rhs: u32) -> Point<T> {
+        Point {
+            x: self.x >> rhs,
+            y: self.y >> rhs,
+        }
+    }
+}
+
+// honors the format spec's precision (rounding each coordinate) and, separately,
+// its width/fill/alignment (e.g. `format!("{:<8}", point)` left-aligns) for the
+// rendered output, falling back to default rendering when neither is specified.
+// We can't delegate the padding step to `f.pad`: it re-interprets precision as a
+// *string* truncation length, which would chop the already-rounded output.
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rendered = match f.precision() {
+            Some(precision) => format!("({:.*}, {:.*})", precision, self.x, precision, self.y),
+            None => format!("({}, {})", self.x, self.y),
+        };
+
+        let width = match f.width() {
+            Some(width) => width,
+            None => return f.write_str(&rendered),
+        };
+
+        let padding = width.saturating_sub(rendered.chars().count());
+        let fill = f.fill();
+        let (left, right) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding),
+        };
+
+        for _ in 0..left {
+            f.write_char(fill)?;
+        }
+        f.write_str(&rendered)?;
+        for _ in 0..right {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
 fn main() {
     // Listing 10-6: A Point<T> struct that holds x and y values of type T
     let integer = Point { x: 5, y: 10 };
     let float = Point { x: 1.0, y: 4.0 };
 
+    println!("{:.2}", float);
+
     // Listing 10-7: The fields x and y must be the same type because both have the same
     // generic data type T
     // let wont_work = Point { x: 5, y: 4.0 };
@@ -25,3 +128,72 @@ fn main() {
     // different types
     let integer_and_float = PointV2 { x: 5, y: 4.0 };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_integer_points() {
+        let a = Point { x: 1, y: 2 };
+        let b = Point { x: 3, y: 4 };
+
+        assert_eq!(Point { x: 4, y: 6 }, a + b);
+    }
+
+    #[test]
+    fn shift_integer_points() {
+        let p = Point { x: 1, y: 2 };
+
+        assert_eq!(Point { x: 4, y: 8 }, p << 2);
+    }
+
+    #[test]
+    fn multiply_float_points() {
+        let a = Point { x: 1.5, y: 2.0 };
+        let b = Point { x: 2.0, y: 3.0 };
+
+        assert_eq!(Point { x: 3.0, y: 6.0 }, a * b);
+    }
+
+    #[test]
+    fn display_with_precision() {
+        let p = Point { x: 1.2345, y: 2.6789 };
+
+        assert_eq!("(1.23, 2.68)", format!("{:.2}", p));
+    }
+
+    #[test]
+    fn display_with_width() {
+        let p = Point { x: 1, y: 2 };
+
+        assert_eq!("  (1, 2)", format!("{:>8}", p));
+    }
+
+    #[test]
+    fn display_honors_fill_and_alignment() {
+        let p = Point { x: 1, y: 2 };
+
+        assert_eq!("(1, 2)--", format!("{:-<8}", p));
+    }
+
+    #[test]
+    fn points_as_hashmap_keys() {
+        use std::collections::HashMap;
+
+        let mut labels = HashMap::new();
+        labels.insert(Point { x: 0, y: 0 }, "origin");
+        labels.insert(Point { x: 1, y: 2 }, "a");
+        labels.insert(Point { x: 3, y: 4 }, "b");
+
+        assert_eq!(Some(&"origin"), labels.get(&Point { x: 0, y: 0 }));
+        assert_eq!(Some(&"a"), labels.get(&Point { x: 1, y: 2 }));
+        assert_eq!(Some(&"b"), labels.get(&Point { x: 3, y: 4 }));
+
+        // a structurally equal point collides with the existing entry rather than
+        // creating a new one
+        labels.insert(Point { x: 1, y: 2 }, "a again");
+        assert_eq!(3, labels.len());
+        assert_eq!(Some(&"a again"), labels.get(&Point { x: 1, y: 2 }));
+    }
+}
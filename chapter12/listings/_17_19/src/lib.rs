@@ -1,10 +1,20 @@
+#[derive(Debug, Default)]
+pub struct SearchOptions {
+    pub case_insensitive: bool,
+    pub whole_word: bool,
+}
+
 pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    search_with(query, contents, &SearchOptions::default())
+}
+
+pub fn search_with<'a>(query: &str, contents: &'a str, opts: &SearchOptions) -> Vec<&'a str> {
     let mut results = Vec::new();
 
     // Listing 12-17: Iterating through each line in contents
     for line in contents.lines() {
         // Listing 12-18: Adding functionality to see whether the line contains the string in query
-        if line.contains(query) {
+        if line_matches(query, line, opts) {
             // Listing 12-19: Storing the lines that match so we can return them
             results.push(line);
         }
@@ -13,6 +23,51 @@ pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
     results
 }
 
+fn line_matches(query: &str, line: &str, opts: &SearchOptions) -> bool {
+    let (query, line) = if opts.case_insensitive {
+        (query.to_lowercase(), line.to_lowercase())
+    } else {
+        (query.to_string(), line.to_string())
+    };
+
+    if opts.whole_word {
+        contains_whole_word(&query, &line)
+    } else {
+        line.contains(&query)
+    }
+}
+
+// a hit counts only when it's bounded by non-alphanumeric characters or the
+// string's edges, so "duct" doesn't match inside "conduct"
+fn contains_whole_word(query: &str, line: &str) -> bool {
+    if query.is_empty() {
+        return false;
+    }
+
+    let mut start = 0;
+    while let Some(pos) = line[start..].find(query) {
+        let match_start = start + pos;
+        let match_end = match_start + query.len();
+
+        let before_ok = line[..match_start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let after_ok = line[match_end..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric());
+
+        if before_ok && after_ok {
+            return true;
+        }
+
+        start = match_end;
+    }
+
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,4 +80,51 @@ safe, fast, productive.
 Pick three.";
         assert_eq!(vec!["safe, fast, productive."], search(query, contents));
     }
+
+    #[test]
+    fn case_insensitive() {
+        let query = "rUsT";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Trust me.";
+
+        let opts = SearchOptions {
+            case_insensitive: true,
+            whole_word: false,
+        };
+
+        assert_eq!(
+            vec!["Rust:", "Trust me."],
+            search_with(query, contents, &opts)
+        );
+    }
+
+    #[test]
+    fn whole_word_rejects_substring_of_a_larger_word() {
+        let query = "duct";
+        let contents = "\
+productive conduct
+duct tape";
+
+        let opts = SearchOptions {
+            case_insensitive: false,
+            whole_word: true,
+        };
+
+        assert_eq!(vec!["duct tape"], search_with(query, contents, &opts));
+    }
+
+    #[test]
+    fn whole_word_does_not_panic_on_multibyte_boundaries() {
+        let query = "éa";
+        let contents = "xéab éa";
+
+        let opts = SearchOptions {
+            case_insensitive: false,
+            whole_word: true,
+        };
+
+        assert_eq!(vec!["xéab éa"], search_with(query, contents, &opts));
+    }
 }
@@ -26,6 +26,12 @@ impl Post {
         self.state.as_ref().unwrap().content(&self)
     }
 
+    pub fn reject(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.reject())
+        }
+    }
+
     pub fn request_review(&mut self) {
         // We need to set state to None temporarily rather than setting it directly with code
         // like self.state = self.state.request_review(); to get ownership of the state value.
@@ -42,6 +48,7 @@ trait State {
     fn content<'a>(&self, post: &'a Post) -> &'a str {
         ""
     }
+    fn reject(self: Box<Self>) -> Box<dyn State>;
     fn request_review(self: Box<Self>) -> Box<dyn State>;
 }
 
@@ -52,16 +59,32 @@ impl State for Draft {
         self
     }
 
+    // Draft has nothing pending to reject, so it stays put
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
     fn request_review(self: Box<Self>) -> Box<dyn State> {
-        Box::new(PendingReview {})
+        Box::new(PendingReview { approval_count: 0 })
     }
 }
 
-struct PendingReview {}
+struct PendingReview {
+    approval_count: u8,
+}
 
 impl State for PendingReview {
-    fn approve(self: Box<Self>) -> Box<dyn State> {
-        Box::new(Published {})
+    fn approve(mut self: Box<Self>) -> Box<dyn State> {
+        self.approval_count += 1;
+        if self.approval_count >= 2 {
+            Box::new(Published {})
+        } else {
+            self
+        }
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        Box::new(Draft {})
     }
 
     fn request_review(self: Box<Self>) -> Box<dyn State> {
@@ -80,7 +103,55 @@ impl State for Published {
         &post.content
     }
 
+    // a published post stays published
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
     fn request_review(self: Box<Self>) -> Box<dyn State> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_sends_pending_review_back_to_draft() {
+        let mut post = Post::new();
+        post.add_text("I ate a salad for lunch today");
+        post.request_review();
+        post.reject();
+
+        assert_eq!("", post.content());
+
+        // the post must go through review again and be approved twice from scratch
+        post.request_review();
+        post.approve();
+        assert_eq!("", post.content());
+        post.approve();
+        assert_eq!("I ate a salad for lunch today", post.content());
+    }
+
+    #[test]
+    fn one_approval_is_not_enough_to_publish() {
+        let mut post = Post::new();
+        post.add_text("I ate a salad for lunch today");
+        post.request_review();
+        post.approve();
+
+        assert_eq!("", post.content());
+    }
+
+    #[test]
+    fn two_approvals_publish_the_post() {
+        let mut post = Post::new();
+        post.add_text("I ate a salad for lunch today");
+        post.request_review();
+        post.approve();
+        post.approve();
+
+        assert_eq!("I ate a salad for lunch today", post.content());
+    }
+}